@@ -10,6 +10,14 @@ use std::io;
 
 const MIN_TABLE_SIZE: usize = 32;
 
+// SwissTable-style group size: the number of control bytes scanned
+// together by a generated `build_swiss` lookup.
+const GROUP_SIZE: usize = 16;
+
+// Empty-slot control byte marker. Any other byte is an occupied slot's H2
+// tag. (No tombstone marker yet -- `Builder` has no removal API.)
+pub const EMPTY: u8 = 0xFF;
+
 #[derive(Default, Debug)]
 pub struct Entry<K> {
   pub key:   K,
@@ -17,9 +25,11 @@ pub struct Entry<K> {
 }
 
 pub struct Builder<K, S> {
-  pub hashes:  Vec<usize>,
-  pub entries: Vec<Entry<K>>,
-  pub hasher:  S,
+  pub hashes:     Vec<usize>,
+  pub raw_hashes: Vec<usize>,
+  pub entries:    Vec<Entry<K>>,
+  pub occupied:   Vec<bool>,
+  pub hasher:     S,
 }
 
 impl<K, S> Builder<K, S>
@@ -35,15 +45,18 @@ impl<K, S> Builder<K, S>
     }
 
     Builder {
-      hashes:  vec![0; cap],
-      entries: entries,
-      hasher:  hasher,
+      hashes:     vec![0; cap],
+      raw_hashes: vec![0; cap],
+      entries:    entries,
+      occupied:   vec![false; cap],
+      hasher:     hasher,
     }
   }
 
   pub fn insert(&mut self, key: K, value: String) -> usize {
     let mask = self.entries.len() - 1;
-    let mut hash = self.hash(&key);
+    let mut raw  = self.hash(&key);
+    let mut hash = if raw == 0 { 1 } else { raw };
     let mut pos  = hash & mask;
     let mut dist = 0;
 
@@ -54,11 +67,15 @@ impl<K, S> Builder<K, S>
 
     loop {
       let probe_hash = unsafe { self.hashes.get_unchecked_mut(pos) };
+      let probe_raw = unsafe { self.raw_hashes.get_unchecked_mut(pos) };
+      let probe_occupied = unsafe { self.occupied.get_unchecked_mut(pos) };
 
       // Found an empty bucket.  Place hash and return.
-      if *probe_hash == 0 {
+      if !*probe_occupied {
         let probe = unsafe { self.entries.get_unchecked_mut(pos) };
         *probe_hash = hash;
+        *probe_raw = raw;
+        *probe_occupied = true;
         *probe = entry;
         return dist
       }
@@ -72,6 +89,7 @@ impl<K, S> Builder<K, S>
         let probe = unsafe { self.entries.get_unchecked_mut(pos) };
         mem::swap(probe, &mut entry);
         mem::swap(probe_hash, &mut hash);
+        mem::swap(probe_raw, &mut raw);
         dist = probe_dist;
       }
 
@@ -100,11 +118,81 @@ impl<K, S> Builder<K, S>
     write!(f, "}};\n\n")
   }
 
+  // Emits the table as a SwissTable-style control/entries pair instead of
+  // the Robin-Hood `hashes`/`entries` pair `build` produces.  Each hash is
+  // split into H1 (`hash >> 7`, which picks the starting group) and H2 (the
+  // low 7 bits, stored as the slot's control byte) so a generated lookup
+  // can group-scan 16 control bytes at a time instead of walking displacement
+  // distances one slot at a time.
+  pub fn build_swiss<W>(&self, f: &mut W) -> io::Result<()>
+    where W: io::Write
+  {
+    let (control, order) = self.swiss_layout();
+
+    write!(f, "SwissMap {{\n control: &[")?;
+
+    for byte in control.iter() {
+        write!(f, "{}, ", byte)?;
+    }
+
+    write!(f, "  ],\n  entries: &[  \n")?;
+
+    for slot in order.iter() {
+      match *slot {
+        Some(pos) => write!(f, "{}, ", self.entries[pos])?,
+        None      => write!(f, "{}, ", Entry::<K>::default())?,
+      };
+    }
+
+    write!(f, "  ],\n")?;
+    write!(f, "  hasher: {:?},", self.hasher)?;
+    write!(f, "}};\n\n")
+  }
+
+  // Re-places every occupied entry into a fresh SwissTable-style layout:
+  // starting at the group given by H1 and linearly advancing by whole
+  // groups on a full-group miss, rather than the single-slot displacement
+  // `insert` uses for the Robin-Hood layout.
+  fn swiss_layout(&self) -> (Vec<u8>, Vec<Option<usize>>) {
+    let cap = self.entries.len();
+    let group_mask = cap / GROUP_SIZE - 1;
+
+    let mut control = vec![EMPTY; cap];
+    let mut order: Vec<Option<usize>> = vec![None; cap];
+
+    for pos in 0..cap {
+      if !self.occupied[pos] { continue }
+
+      let hash = self.raw_hashes[pos];
+      let h2 = (hash & 0x7f) as u8;
+      let mut group = (hash >> 7) & group_mask;
+
+      loop {
+        let start = group * GROUP_SIZE;
+
+        if let Some(slot) = (start..start + GROUP_SIZE).find(|&i| control[i] == EMPTY) {
+          control[slot] = h2;
+          order[slot] = Some(pos);
+          break
+        }
+
+        group = (group + 1) & group_mask;
+      }
+    }
+
+    (control, order)
+  }
+
+  // Returns the key's true hasher output, unmodified.  `insert` replaces a
+  // 0 hash with 1 in its own copy before storing into `self.hashes`, because
+  // `build()` still emits `self.hashes` verbatim and its consumer treats a
+  // 0 entry as the empty-slot marker.  `self.raw_hashes` keeps this
+  // unsubstituted value so `build_swiss`'s H1/H2 split matches what an
+  // independent consumer computes from the real hash of a query key.
   fn hash(&self, key: &K) -> usize {
     let mut hasher = self.hasher.build_hasher();
     key.hash(&mut hasher);
-    let hash =  hasher.finish() as usize;
-    if hash == 0 { 1 } else { hash }
+    hasher.finish() as usize
   }
 }
 
@@ -113,4 +201,81 @@ impl<K> fmt::Display for Entry<K>
   fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
     write!(f, "({}, {})", self.key, self.value)
   }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  // Hands `hash()` the key's own bits verbatim, so tests can pick hashes
+  // that land exactly on the H2 tag boundaries (0x7f, 0x80, 0xff).
+  #[derive(Default)]
+  struct IdentityHasher(u64);
+
+  impl Hasher for IdentityHasher {
+    fn write(&mut self, bytes: &[u8]) {
+      let mut buf = [0u8; 8];
+      let n = bytes.len().min(8);
+      buf[..n].copy_from_slice(&bytes[..n]);
+      self.0 = u64::from_ne_bytes(buf);
+    }
+
+    fn finish(&self) -> u64 { self.0 }
+  }
+
+  #[derive(Debug, Default, Clone, Copy)]
+  struct IdentityBuildHasher;
+
+  impl BuildHasher for IdentityBuildHasher {
+    type Hasher = IdentityHasher;
+    fn build_hasher(&self) -> IdentityHasher { IdentityHasher::default() }
+  }
+
+  // Replays the lookup a generated `build_swiss` consumer would do: probe
+  // the group at `hash >> 7`, scan its 16 control bytes for the H2 tag,
+  // and stop at the first `EMPTY` slot.
+  fn swiss_lookup(control: &[u8], order: &[Option<usize>], entries: &[Entry<u64>], hash: usize) -> Option<usize> {
+    let h2 = (hash & 0x7f) as u8;
+    let group_mask = control.len() / GROUP_SIZE - 1;
+    let mut group = (hash >> 7) & group_mask;
+
+    loop {
+      let start = group * GROUP_SIZE;
+
+      for i in start..start + GROUP_SIZE {
+        if control[i] == EMPTY { return None }
+
+        if control[i] == h2 {
+          if let Some(pos) = order[i] {
+            if entries[pos].key == hash as u64 { return Some(pos) }
+          }
+        }
+      }
+
+      group = (group + 1) & group_mask;
+    }
+  }
+
+  #[test]
+  fn build_swiss_keeps_every_inserted_key_reachable() {
+    let mut builder = Builder::<u64, _>::with_capacity(8, IdentityBuildHasher);
+
+    // Chosen to land on/either side of the EMPTY (0xff) control byte, plus
+    // a 0 hash -- `build()`'s Robin-Hood path substitutes that to 1, but
+    // `build_swiss` must not: an independent consumer hashing the same key
+    // computes `h2`/`group` straight from the true hash of 0.
+    let hashes: Vec<u64> = vec![0, 1, 0x7e, 0x7f, 0x80, 0xff, 0x100, 0x17f];
+
+    for &hash in &hashes {
+      builder.insert(hash, format!("v{}", hash));
+    }
+
+    let (control, order) = builder.swiss_layout();
+
+    for &hash in &hashes {
+      let pos = swiss_lookup(&control, &order, &builder.entries, hash as usize)
+        .unwrap_or_else(|| panic!("key with hash {:#x} not reachable in swiss layout", hash));
+      assert_eq!(builder.entries[pos].key, hash);
+    }
+  }
 }
\ No newline at end of file